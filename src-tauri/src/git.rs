@@ -0,0 +1,264 @@
+use git2::{DiffOptions, Repository, StatusOptions};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitStatus {
+    pub branch: Option<String>,
+    pub ahead: u32,
+    pub behind: u32,
+    pub staged: Vec<GitFile>,
+    pub unstaged: Vec<GitFile>,
+    pub untracked: Vec<String>,
+    pub current_commit: Option<GitCommit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitFile {
+    pub path: String,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitCommit {
+    pub hash: String,
+    pub author: String,
+    pub message: String,
+    pub date: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitDiff {
+    pub file: String,
+    pub hunks: Vec<GitHunk>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHunk {
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    pub lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitBranch {
+    pub name: String,
+    #[serde(rename = "isCurrent")]
+    pub is_current: bool,
+}
+
+fn open_repo(cwd: &str) -> Result<Repository, String> {
+    Repository::open(cwd).map_err(|e| format!("Failed to open git repo at {}: {}", cwd, e))
+}
+
+fn current_commit(repo: &Repository) -> Option<GitCommit> {
+    let head = repo.head().ok()?;
+    let commit = head.peel_to_commit().ok()?;
+    Some(GitCommit {
+        hash: commit.id().to_string(),
+        author: commit.author().name().unwrap_or("unknown").to_string(),
+        message: commit.message().unwrap_or("").trim().to_string(),
+        date: commit.time().seconds(),
+    })
+}
+
+fn ahead_behind(repo: &Repository) -> (u32, u32) {
+    let head = match repo.head().ok().and_then(|h| h.target()) {
+        Some(oid) => oid,
+        None => return (0, 0),
+    };
+
+    let upstream = repo
+        .head()
+        .ok()
+        .and_then(|h| h.shorthand().map(|s| s.to_string()))
+        .and_then(|branch_name| repo.find_branch(&branch_name, git2::BranchType::Local).ok())
+        .and_then(|branch| branch.upstream().ok())
+        .and_then(|upstream| upstream.get().target());
+
+    match upstream {
+        Some(upstream_oid) => repo
+            .graph_ahead_behind(head, upstream_oid)
+            .map(|(ahead, behind)| (ahead as u32, behind as u32))
+            .unwrap_or((0, 0)),
+        None => (0, 0),
+    }
+}
+
+#[tauri::command]
+pub fn git_status(cwd: String) -> Result<GitStatus, String> {
+    let repo = open_repo(&cwd)?;
+
+    let branch = repo
+        .head()
+        .ok()
+        .and_then(|h| h.shorthand().map(|s| s.to_string()));
+    let (ahead, behind) = ahead_behind(&repo);
+
+    let mut opts = StatusOptions::new();
+    opts.include_untracked(true);
+
+    let statuses = repo
+        .statuses(Some(&mut opts))
+        .map_err(|e| format!("Failed to read status: {}", e))?;
+
+    let mut staged = Vec::new();
+    let mut unstaged = Vec::new();
+    let mut untracked = Vec::new();
+
+    for entry in statuses.iter() {
+        let status = entry.status();
+        let path = match entry.path() {
+            Some(p) => p.to_string(),
+            None => continue,
+        };
+
+        if status.is_wt_new() {
+            untracked.push(path.clone());
+            continue;
+        }
+
+        if status.is_index_new() {
+            staged.push(GitFile { path: path.clone(), status: "added".to_string() });
+        } else if status.is_index_modified() {
+            staged.push(GitFile { path: path.clone(), status: "modified".to_string() });
+        } else if status.is_index_deleted() {
+            staged.push(GitFile { path: path.clone(), status: "deleted".to_string() });
+        } else if status.is_index_renamed() {
+            staged.push(GitFile { path: path.clone(), status: "renamed".to_string() });
+        }
+
+        if status.is_wt_modified() {
+            unstaged.push(GitFile { path: path.clone(), status: "modified".to_string() });
+        } else if status.is_wt_deleted() {
+            unstaged.push(GitFile { path: path.clone(), status: "deleted".to_string() });
+        } else if status.is_wt_renamed() {
+            unstaged.push(GitFile { path, status: "renamed".to_string() });
+        }
+    }
+
+    Ok(GitStatus {
+        branch,
+        ahead,
+        behind,
+        staged,
+        unstaged,
+        untracked,
+        current_commit: current_commit(&repo),
+    })
+}
+
+#[tauri::command]
+pub fn git_diff(cwd: String, file: String) -> Result<GitDiff, String> {
+    let repo = open_repo(&cwd)?;
+
+    let mut opts = DiffOptions::new();
+    opts.pathspec(&file);
+
+    let head_tree = repo.head().ok().and_then(|h| h.peel_to_tree().ok());
+
+    let diff = repo
+        .diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut opts))
+        .map_err(|e| format!("Failed to diff: {}", e))?;
+
+    let mut hunks = Vec::new();
+
+    for delta_idx in 0..diff.deltas().len() {
+        let patch = git2::Patch::from_diff(&diff, delta_idx)
+            .map_err(|e| format!("Failed to build patch: {}", e))?;
+        let Some(mut patch) = patch else { continue };
+
+        for hunk_idx in 0..patch.num_hunks() {
+            let (hunk, line_count) = patch
+                .hunk(hunk_idx)
+                .map_err(|e| format!("Failed to read hunk: {}", e))?;
+
+            let mut lines = Vec::with_capacity(line_count);
+            for line_idx in 0..line_count {
+                let line = patch
+                    .line_in_hunk(hunk_idx, line_idx)
+                    .map_err(|e| format!("Failed to read hunk line: {}", e))?;
+                let prefix = match line.origin() {
+                    '+' => "+",
+                    '-' => "-",
+                    _ => " ",
+                };
+                let content = String::from_utf8_lossy(line.content()).trim_end().to_string();
+                lines.push(format!("{}{}", prefix, content));
+            }
+
+            hunks.push(GitHunk {
+                old_start: hunk.old_start(),
+                old_lines: hunk.old_lines(),
+                new_start: hunk.new_start(),
+                new_lines: hunk.new_lines(),
+                lines,
+            });
+        }
+    }
+
+    Ok(GitDiff { file, hunks })
+}
+
+#[tauri::command]
+pub fn git_branches(cwd: String) -> Result<Vec<GitBranch>, String> {
+    let repo = open_repo(&cwd)?;
+
+    let current = repo
+        .head()
+        .ok()
+        .and_then(|h| h.shorthand().map(|s| s.to_string()));
+
+    let branches = repo
+        .branches(Some(git2::BranchType::Local))
+        .map_err(|e| format!("Failed to list branches: {}", e))?;
+
+    let mut result = Vec::new();
+    for branch in branches {
+        let (branch, _) = branch.map_err(|e| format!("Failed to read branch: {}", e))?;
+        let name = match branch.name().map_err(|e| e.to_string())? {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        let is_current = current.as_deref() == Some(name.as_str());
+        result.push(GitBranch { name, is_current });
+    }
+
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn git_stage(cwd: String, path: String) -> Result<String, String> {
+    let repo = open_repo(&cwd)?;
+    let mut index = repo.index().map_err(|e| format!("Failed to open index: {}", e))?;
+
+    index
+        .add_path(std::path::Path::new(&path))
+        .map_err(|e| format!("Failed to stage {}: {}", path, e))?;
+    index.write().map_err(|e| format!("Failed to write index: {}", e))?;
+
+    Ok(format!("Staged {}", path))
+}
+
+#[tauri::command]
+pub fn git_commit(cwd: String, message: String) -> Result<String, String> {
+    let repo = open_repo(&cwd)?;
+
+    let mut index = repo.index().map_err(|e| format!("Failed to open index: {}", e))?;
+    let tree_id = index.write_tree().map_err(|e| format!("Failed to write tree: {}", e))?;
+    let tree = repo.find_tree(tree_id).map_err(|e| format!("Failed to find tree: {}", e))?;
+
+    let signature = repo
+        .signature()
+        .map_err(|e| format!("Failed to read git signature (set user.name/user.email): {}", e))?;
+
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+
+    let commit_id = repo
+        .commit(Some("HEAD"), &signature, &signature, &message, &tree, &parents)
+        .map_err(|e| format!("Failed to commit: {}", e))?;
+
+    Ok(commit_id.to_string())
+}
@@ -1,56 +1,14 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io::{BufRead, BufReader, Write};
 use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tauri::{Emitter, Manager, State};
 
-// Git types
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GitStatus {
-    pub branch: Option<String>,
-    pub ahead: u32,
-    pub behind: u32,
-    pub staged: Vec<GitFile>,
-    pub unstaged: Vec<GitFile>,
-    pub untracked: Vec<String>,
-    pub current_commit: Option<GitCommit>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GitFile {
-    pub path: String,
-    pub status: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GitCommit {
-    pub hash: String,
-    pub author: String,
-    pub message: String,
-    pub date: i64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GitDiff {
-    pub file: String,
-    pub hunks: Vec<GitHunk>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GitHunk {
-    pub old_start: u32,
-    pub old_lines: u32,
-    pub new_start: u32,
-    pub new_lines: u32,
-    pub lines: Vec<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GitBranch {
-    pub name: String,
-    #[serde(rename = "isCurrent")]
-    pub is_current: bool,
-}
+mod git;
+mod updater;
 
 // ========== Node Detection ==========
 
@@ -62,8 +20,19 @@ pub struct NodeInfo {
     pub error: Option<String>,
 }
 
+/// Minimum supported Node.js major version for the sidecar.
+const MIN_NODE_MAJOR: u32 = 18;
+
 /// Check common Node.js installation paths
 fn find_node_path() -> Option<String> {
+    if cfg!(windows) {
+        find_node_path_windows()
+    } else {
+        find_node_path_unix()
+    }
+}
+
+fn find_node_path_unix() -> Option<String> {
     let home = std::env::var("HOME").unwrap_or_default();
 
     let paths = vec![
@@ -96,6 +65,44 @@ fn find_node_path() -> Option<String> {
     None
 }
 
+fn find_node_path_windows() -> Option<String> {
+    let program_files = std::env::var("ProgramFiles").unwrap_or_else(|_| "C:\\Program Files".to_string());
+    let appdata = std::env::var("APPDATA").unwrap_or_default();
+    let local_appdata = std::env::var("LOCALAPPDATA").unwrap_or_default();
+    let home = std::env::var("USERPROFILE").unwrap_or_default();
+
+    let paths = vec![
+        format!("{program_files}\\nodejs\\node.exe"),              // Default installer
+        format!("{appdata}\\nvm\\current\\node.exe"),               // nvm-windows
+        format!("{local_appdata}\\fnm_multishells\\node.exe"),      // fnm
+        format!("{local_appdata}\\Volta\\bin\\node.exe"),           // Volta
+        format!("{home}\\AppData\\Roaming\\npm\\node.exe"),         // npm global install
+    ];
+
+    for path in paths {
+        if std::path::Path::new(&path).exists() {
+            return Some(path);
+        }
+    }
+
+    // Try `where node` as fallback
+    if let Ok(output) = Command::new("where").arg("node").output() {
+        if output.status.success() {
+            let path = String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .next()
+                .unwrap_or("")
+                .trim()
+                .to_string();
+            if !path.is_empty() && std::path::Path::new(&path).exists() {
+                return Some(path);
+            }
+        }
+    }
+
+    None
+}
+
 /// Get Node.js version from a given path
 fn get_node_version(node_path: &str) -> Option<String> {
     if let Ok(output) = Command::new(node_path).arg("--version").output() {
@@ -106,31 +113,45 @@ fn get_node_version(node_path: &str) -> Option<String> {
     None
 }
 
+/// Parse the major version out of a Node `--version` string like `v18.17.0`.
+fn node_major_version(version: &str) -> Option<u32> {
+    version.trim_start_matches('v').split('.').next()?.parse().ok()
+}
+
 #[tauri::command]
 fn detect_node() -> NodeInfo {
     match find_node_path() {
         Some(path) => {
             let version = get_node_version(&path);
+
+            let error = match version.as_deref().and_then(node_major_version) {
+                Some(major) if major < MIN_NODE_MAJOR => Some(format!(
+                    "Node {}+ required, found {}",
+                    MIN_NODE_MAJOR,
+                    version.as_deref().unwrap_or("unknown")
+                )),
+                _ => None,
+            };
+
             NodeInfo {
                 found: true,
                 path: Some(path),
                 version,
-                error: None,
+                error,
             }
         }
         None => NodeInfo {
             found: false,
             path: None,
             version: None,
-            error: Some("Node.js not found. Please install Node.js 18+".to_string()),
+            error: Some(format!("Node.js not found. Please install Node.js {MIN_NODE_MAJOR}+")),
         },
     }
 }
 
 // ========== Sidecar Management ==========
 
-// JSON-RPC types (reserved for future use)
-#[allow(dead_code)]
+// JSON-RPC types
 #[derive(Debug, Serialize, Deserialize)]
 struct JsonRpcRequest {
     jsonrpc: String,
@@ -139,7 +160,6 @@ struct JsonRpcRequest {
     params: serde_json::Value,
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize)]
 struct JsonRpcMessage {
     jsonrpc: String,
@@ -155,12 +175,39 @@ struct JsonRpcMessage {
     params: Option<serde_json::Value>,
 }
 
+/// Default timeout for a `call_sidecar` round trip.
+const SIDECAR_CALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Initial delay before the first auto-restart attempt, doubled on each
+/// consecutive failure up to `RESTART_BACKOFF_CAP`.
+const RESTART_BACKOFF_BASE: Duration = Duration::from_millis(500);
+const RESTART_BACKOFF_CAP: Duration = Duration::from_secs(30);
+/// How long the sidecar must stay up before a restart is no longer considered
+/// a "flapping" failure, resetting the backoff counter.
+const RESTART_STABLE_UPTIME: Duration = Duration::from_secs(60);
+/// Heartbeat cadence and failure threshold before the supervisor kills and
+/// restarts an unresponsive sidecar.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(5);
+const HEARTBEAT_MAX_FAILURES: u32 = 3;
+
 // Sidecar state now only holds stdin (stdout is moved to reader thread)
 struct SidecarState {
     stdin: Option<ChildStdin>,
     child: Option<Child>,
     node_path: Option<String>,
+    sidecar_path: Option<std::path::PathBuf>,
+    sidecar_cwd: Option<std::path::PathBuf>,
     running: bool,
+    next_id: u64,
+    pending: HashMap<String, mpsc::Sender<serde_json::Value>>,
+    /// Generation bumped on every (re)spawn so stale reader/heartbeat threads
+    /// from a previous process incarnation know to stop touching state.
+    generation: u64,
+    auto_restart: bool,
+    restart_count: u32,
+    /// Set by `stop_sidecar` so the exit handler knows not to respawn.
+    manual_stop: bool,
 }
 
 impl Default for SidecarState {
@@ -169,27 +216,35 @@ impl Default for SidecarState {
             stdin: None,
             child: None,
             node_path: None,
+            sidecar_path: None,
+            sidecar_cwd: None,
             running: false,
+            next_id: 0,
+            pending: HashMap::new(),
+            generation: 0,
+            auto_restart: true,
+            restart_count: 0,
+            manual_stop: false,
         }
     }
 }
 
 type SidecarMutex = Arc<Mutex<SidecarState>>;
 
-#[tauri::command]
-fn start_sidecar(state: State<'_, SidecarMutex>, app: tauri::AppHandle) -> Result<String, String> {
-    // Check if already running
-    {
-        let sidecar = state.lock().map_err(|e| e.to_string())?;
-        if sidecar.running {
-            return Ok("Sidecar already running".to_string());
-        }
-    }
-
-    // Find Node.js
-    let node_path = find_node_path().ok_or("Node.js not found")?;
+/// Resolve the sidecar's node binary and entry point, preferring the
+/// development location (`project_root/sidecar/dist/index.cjs`, which keeps
+/// `node_modules` available for native modules like better-sqlite3) over the
+/// bundled resource.
+fn resolve_sidecar(
+    app: &tauri::AppHandle,
+    node_path_override: Option<String>,
+) -> Result<(String, std::path::PathBuf, Option<std::path::PathBuf>), String> {
+    let node_path = match node_path_override {
+        Some(path) if std::path::Path::new(&path).exists() => path,
+        Some(path) => return Err(format!("node_path_override {} does not exist", path)),
+        None => find_node_path().ok_or("Node.js not found")?,
+    };
 
-    // Get sidecar path from app resources
     let resource_path = app
         .path()
         .resource_dir()
@@ -202,8 +257,6 @@ fn start_sidecar(state: State<'_, SidecarMutex>, app: tauri::AppHandle) -> Resul
         .ok()
         .and_then(|p| p.parent().map(|p| p.to_path_buf()));
 
-    // Try to find sidecar in development location first (project_root/sidecar/dist/index.js)
-    // This ensures node_modules is available for native modules like better-sqlite3
     let project_root = exe_dir.as_ref()
         .and_then(|p| p.parent()) // target
         .and_then(|p| p.parent()) // src-tauri
@@ -229,46 +282,87 @@ fn start_sidecar(state: State<'_, SidecarMutex>, app: tauri::AppHandle) -> Resul
         return Err(format!("Sidecar not found at {:?}", resource_path));
     };
 
-    // Start sidecar process with correct working directory for node_modules resolution
+    Ok((node_path, sidecar_path, sidecar_cwd))
+}
+
+/// Spawn the sidecar process and wire up its stdin/stdout/stderr, the
+/// stdout JSON-RPC reader (which also drives auto-restart on unexpected
+/// exit), and a heartbeat thread. Used both for the initial start and for
+/// supervised restarts.
+fn spawn_sidecar_process(
+    state: &SidecarMutex,
+    app: &tauri::AppHandle,
+    node_path: String,
+    sidecar_path: std::path::PathBuf,
+    sidecar_cwd: Option<std::path::PathBuf>,
+) -> Result<(), String> {
     let mut cmd = Command::new(&node_path);
     cmd.arg(&sidecar_path)
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
 
-    if let Some(cwd) = sidecar_cwd {
+    if let Some(ref cwd) = sidecar_cwd {
         cmd.current_dir(cwd);
     }
 
     let mut child = cmd.spawn()
         .map_err(|e| format!("Failed to start sidecar: {}", e))?;
 
-    // Take ownership of stdin and stdout
     let stdin = child.stdin.take().ok_or("Failed to get stdin")?;
     let stdout = child.stdout.take().ok_or("Failed to get stdout")?;
     let stderr = child.stderr.take().ok_or("Failed to get stderr")?;
 
-    // Update state
-    {
+    let generation = {
         let mut sidecar = state.lock().map_err(|e| e.to_string())?;
         sidecar.stdin = Some(stdin);
         sidecar.child = Some(child);
         sidecar.node_path = Some(node_path.clone());
+        sidecar.sidecar_path = Some(sidecar_path.clone());
+        sidecar.sidecar_cwd = sidecar_cwd.clone();
         sidecar.running = true;
-    }
-
-    // Clone state for the reader threads
-    let state_clone = Arc::clone(&state);
+        sidecar.manual_stop = false;
+        sidecar.generation += 1;
+        sidecar.generation
+    };
 
-    // Spawn stdout reader thread
+    let state_clone = Arc::clone(state);
     let app_handle = app.app_handle().clone();
+
+    // Spawn stdout reader thread; also owns the auto-restart decision on exit.
     std::thread::spawn(move || {
         let reader = BufReader::new(stdout);
         for line in reader.lines() {
             match line {
                 Ok(content) => {
-                    if !content.is_empty() {
-                        let _ = app_handle.emit("sidecar-message", &content);
+                    if content.is_empty() {
+                        continue;
+                    }
+
+                    match serde_json::from_str::<JsonRpcMessage>(&content) {
+                        Ok(msg) if msg.id.is_some() => {
+                            let id = msg.id.unwrap();
+                            let sender = state_clone
+                                .lock()
+                                .ok()
+                                .and_then(|mut sidecar| sidecar.pending.remove(&id));
+
+                            if let Some(sender) = sender {
+                                let payload = msg.error.unwrap_or_else(|| {
+                                    msg.result.unwrap_or(serde_json::Value::Null)
+                                });
+                                let _ = sender.send(payload);
+                            } else {
+                                // No one is waiting for this id anymore; surface it raw.
+                                let _ = app_handle.emit("sidecar-message", &content);
+                            }
+                        }
+                        Ok(msg) if msg.method.is_some() => {
+                            let _ = app_handle.emit("sidecar-notification", &content);
+                        }
+                        _ => {
+                            let _ = app_handle.emit("sidecar-message", &content);
+                        }
                     }
                 }
                 Err(e) => {
@@ -278,11 +372,72 @@ fn start_sidecar(state: State<'_, SidecarMutex>, app: tauri::AppHandle) -> Resul
             }
         }
 
-        // Mark as not running when stdout closes
-        if let Ok(mut sidecar) = state_clone.lock() {
+        let (manual_stop, auto_restart, restart_count) = {
+            let mut sidecar = match state_clone.lock() {
+                Ok(sidecar) => sidecar,
+                Err(_) => return,
+            };
+            // A newer process has already been spawned; this reader is stale.
+            if sidecar.generation != generation {
+                return;
+            }
             sidecar.running = false;
-        }
+            (sidecar.manual_stop, sidecar.auto_restart, sidecar.restart_count)
+        };
         let _ = app_handle.emit("sidecar-exit", ());
+
+        if manual_stop || !auto_restart {
+            return;
+        }
+
+        let delay = std::cmp::min(
+            RESTART_BACKOFF_BASE.saturating_mul(1u32 << restart_count.min(16)),
+            RESTART_BACKOFF_CAP,
+        );
+        let _ = app_handle.emit(
+            "sidecar-restarting",
+            serde_json::json!({ "attempt": restart_count + 1, "delay_ms": delay.as_millis() as u64 }),
+        );
+        std::thread::sleep(delay);
+
+        let (node_path, sidecar_path, sidecar_cwd) = {
+            let mut sidecar = match state_clone.lock() {
+                Ok(sidecar) => sidecar,
+                Err(_) => return,
+            };
+
+            // The user may have called stop_sidecar() (or start_sidecar())
+            // while we were sleeping through the backoff delay; don't respawn
+            // out from under them.
+            if sidecar.manual_stop || sidecar.generation != generation {
+                return;
+            }
+
+            sidecar.restart_count += 1;
+            match (sidecar.node_path.clone(), sidecar.sidecar_path.clone()) {
+                (Some(node_path), Some(sidecar_path)) => {
+                    (node_path, sidecar_path, sidecar.sidecar_cwd.clone())
+                }
+                _ => return,
+            }
+        };
+
+        if let Err(e) = spawn_sidecar_process(&state_clone, &app_handle, node_path, sidecar_path, sidecar_cwd) {
+            let _ = app_handle.emit("sidecar-error", format!("Restart failed: {}", e));
+            return;
+        }
+
+        // Reset the backoff counter once the respawned sidecar has proven stable.
+        let reset_state = Arc::clone(&state_clone);
+        let watch_generation = generation + 1;
+        std::thread::spawn(move || {
+            std::thread::sleep(RESTART_STABLE_UPTIME);
+            if let Ok(mut sidecar) = reset_state.lock() {
+                if sidecar.generation == watch_generation && sidecar.running {
+                    sidecar.restart_count = 0;
+                }
+            }
+        });
     });
 
     // Spawn stderr reader thread
@@ -298,6 +453,69 @@ fn start_sidecar(state: State<'_, SidecarMutex>, app: tauri::AppHandle) -> Resul
         }
     });
 
+    spawn_heartbeat(Arc::clone(state), app.clone(), generation);
+
+    Ok(())
+}
+
+/// Periodically pings the sidecar; after `HEARTBEAT_MAX_FAILURES` consecutive
+/// timeouts, kills the child so the stdout reader's exit handler restarts it.
+fn spawn_heartbeat(state: SidecarMutex, app: tauri::AppHandle, generation: u64) {
+    std::thread::spawn(move || {
+        let mut consecutive_failures = 0u32;
+
+        loop {
+            std::thread::sleep(HEARTBEAT_INTERVAL);
+
+            let still_current = matches!(
+                state.lock().map(|s| s.generation == generation && s.running),
+                Ok(true)
+            );
+            if !still_current {
+                return;
+            }
+
+            match rpc_call(&state, "ping".to_string(), serde_json::Value::Null, HEARTBEAT_TIMEOUT) {
+                Ok(_) => consecutive_failures = 0,
+                Err(_) => {
+                    consecutive_failures += 1;
+                    if consecutive_failures >= HEARTBEAT_MAX_FAILURES {
+                        let _ = app.emit(
+                            "sidecar-error",
+                            format!("Sidecar unresponsive after {} pings, restarting", consecutive_failures),
+                        );
+                        if let Ok(mut sidecar) = state.lock() {
+                            if sidecar.generation == generation {
+                                if let Some(mut child) = sidecar.child.take() {
+                                    let _ = child.kill();
+                                }
+                            }
+                        }
+                        return;
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[tauri::command]
+fn start_sidecar(
+    state: State<'_, SidecarMutex>,
+    app: tauri::AppHandle,
+    node_path_override: Option<String>,
+) -> Result<String, String> {
+    {
+        let sidecar = state.lock().map_err(|e| e.to_string())?;
+        if sidecar.running {
+            return Ok("Sidecar already running".to_string());
+        }
+    }
+
+    let (node_path, sidecar_path, sidecar_cwd) = resolve_sidecar(&app, node_path_override)?;
+    let state_arc = state.inner().clone();
+    spawn_sidecar_process(&state_arc, &app, node_path.clone(), sidecar_path, sidecar_cwd)?;
+
     Ok(format!("Sidecar started with Node at {}", node_path))
 }
 
@@ -305,6 +523,8 @@ fn start_sidecar(state: State<'_, SidecarMutex>, app: tauri::AppHandle) -> Resul
 fn stop_sidecar(state: State<'_, SidecarMutex>) -> Result<String, String> {
     let mut sidecar = state.lock().map_err(|e| e.to_string())?;
 
+    sidecar.manual_stop = true;
+
     if let Some(mut child) = sidecar.child.take() {
         child.kill().map_err(|e| e.to_string())?;
         sidecar.stdin = None;
@@ -328,26 +548,151 @@ fn send_to_sidecar(state: State<'_, SidecarMutex>, message: String) -> Result<St
     Err("Sidecar not running or stdin not available".to_string())
 }
 
+/// Send a JSON-RPC request to the sidecar and block until the matching
+/// response arrives or `timeout` elapses. Shared by the `call_sidecar` and
+/// `ping_sidecar` commands and by the heartbeat thread.
+fn rpc_call(
+    state: &SidecarMutex,
+    method: String,
+    params: serde_json::Value,
+    timeout: Duration,
+) -> Result<serde_json::Value, String> {
+    let (tx, rx) = mpsc::channel();
+
+    let request = {
+        let mut sidecar = state.lock().map_err(|e| e.to_string())?;
+
+        let stdin = sidecar
+            .stdin
+            .as_mut()
+            .ok_or("Sidecar not running or stdin not available")?;
+
+        sidecar.next_id += 1;
+        let id = sidecar.next_id.to_string();
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: id.clone(),
+            method,
+            params,
+        };
+
+        let line = serde_json::to_string(&request).map_err(|e| e.to_string())?;
+        writeln!(stdin, "{}", line).map_err(|e| e.to_string())?;
+        stdin.flush().map_err(|e| e.to_string())?;
+
+        sidecar.pending.insert(id, tx);
+        request
+    };
+
+    rx.recv_timeout(timeout).map_err(|_| {
+        if let Ok(mut sidecar) = state.lock() {
+            sidecar.pending.remove(&request.id);
+        }
+        format!("Timed out waiting for sidecar response to {}", request.method)
+    })
+}
+
+/// Make a JSON-RPC call to the sidecar and block until the matching response arrives
+/// (or `SIDECAR_CALL_TIMEOUT` elapses).
+#[tauri::command]
+fn call_sidecar(
+    state: State<'_, SidecarMutex>,
+    method: String,
+    params: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    rpc_call(state.inner(), method, params, SIDECAR_CALL_TIMEOUT)
+}
+
+/// Ping the sidecar over JSON-RPC to check liveness, independent of the
+/// background heartbeat thread.
+#[tauri::command]
+fn ping_sidecar(state: State<'_, SidecarMutex>) -> Result<serde_json::Value, String> {
+    rpc_call(state.inner(), "ping".to_string(), serde_json::Value::Null, HEARTBEAT_TIMEOUT)
+}
+
 // ========== App Entry ==========
 
-/// Open a path in Finder (reveal in Finder)
+/// Reveal a path in the platform's file manager (Finder, Explorer, or the
+/// Linux desktop's file manager).
 #[tauri::command]
-fn reveal_in_finder(path: String) -> Result<String, String> {
-    use std::process::Command;
+fn reveal_in_file_manager(path: String) -> Result<String, String> {
+    if cfg!(target_os = "windows") {
+        Command::new("explorer")
+            .arg(format!("/select,{}", path))
+            .spawn()
+            .map_err(|e| format!("Failed to open Explorer: {}", e))?;
+    } else if cfg!(target_os = "macos") {
+        Command::new("open")
+            .arg("-R")
+            .arg(&path)
+            .spawn()
+            .map_err(|e| format!("Failed to open Finder: {}", e))?;
+    } else {
+        reveal_in_file_manager_linux(&path)?;
+    }
+
+    Ok(format!("Revealed {} in file manager", path))
+}
+
+/// Linux has no single "reveal" API; try the freedesktop FileManager1 DBus
+/// interface first (it selects the item, like Finder/Explorer do), then fall
+/// back to `xdg-open`-ing the parent directory.
+fn reveal_in_file_manager_linux(path: &str) -> Result<(), String> {
+    let uri = format!("file://{}", path);
+
+    let dbus_ok = Command::new("dbus-send")
+        .args([
+            "--session",
+            "--dest=org.freedesktop.FileManager1",
+            "--type=method_call",
+            "/org/freedesktop/FileManager1",
+            "org.freedesktop.FileManager1.ShowItems",
+            &format!("array:string:{}", uri),
+            "string:",
+        ])
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false);
+
+    if dbus_ok {
+        return Ok(());
+    }
 
-    // Use macOS `open -R` to reveal in Finder
-    Command::new("open")
-        .arg("-R")
-        .arg(&path)
+    let parent = std::path::Path::new(path)
+        .parent()
+        .ok_or("Path has no parent directory")?;
+
+    Command::new("xdg-open")
+        .arg(parent)
         .spawn()
-        .map_err(|e| format!("Failed to open Finder: {}", e))?;
+        .map_err(|e| format!("Failed to open file manager: {}", e))?;
+
+    Ok(())
+}
 
-    Ok(format!("Revealed {} in Finder", path))
+#[derive(Debug, Clone, Serialize)]
+pub struct SavedArtifact {
+    pub path: String,
+    pub size: u64,
 }
 
 /// Save content as an artifact file in the session's working directory
 #[tauri::command]
-fn save_artifact(cwd: String, content: String, filename: String) -> Result<String, String> {
+fn save_artifact(cwd: String, content: String, filename: String) -> Result<SavedArtifact, String> {
+    // Reject anything that isn't a single plain path component. `:` is
+    // rejected outright because a drive-relative name like `C:evil.txt` makes
+    // `Path::join` discard the base path entirely on Windows; the components
+    // check catches separators, `..`, and any other prefix/root forms.
+    let mut components = std::path::Path::new(&filename).components();
+    let is_plain_component = matches!(
+        (components.next(), components.next()),
+        (Some(std::path::Component::Normal(name)), None) if name == filename.as_str()
+    );
+    if filename.is_empty() || filename.contains(':') || !is_plain_component {
+        return Err(format!("Invalid artifact filename: {}", filename));
+    }
+
     let artifacts_dir = std::path::Path::new(&cwd).join(".claude").join("artifacts");
 
     // Ensure directory exists
@@ -358,7 +703,14 @@ fn save_artifact(cwd: String, content: String, filename: String) -> Result<Strin
     std::fs::write(&file_path, &content)
         .map_err(|e| format!("Failed to write file: {}", e))?;
 
-    Ok(file_path.to_string_lossy().to_string())
+    let size = std::fs::metadata(&file_path)
+        .map_err(|e| format!("Failed to read written file metadata: {}", e))?
+        .len();
+
+    Ok(SavedArtifact {
+        path: file_path.to_string_lossy().to_string(),
+        size,
+    })
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -366,14 +718,25 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_updater::Builder::new().build())
         .manage(Arc::new(Mutex::new(SidecarState::default())))
         .invoke_handler(tauri::generate_handler![
             detect_node,
             start_sidecar,
             stop_sidecar,
             send_to_sidecar,
-            reveal_in_finder,
+            call_sidecar,
+            ping_sidecar,
+            reveal_in_file_manager,
             save_artifact,
+            git::git_status,
+            git::git_diff,
+            git::git_branches,
+            git::git_stage,
+            git::git_commit,
+            updater::check_for_update,
+            updater::install_update,
+            updater::skip_update_version,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
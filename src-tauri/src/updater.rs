@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_updater::UpdaterExt;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateInfo {
+    pub available: bool,
+    pub version: Option<String>,
+    pub notes: Option<String>,
+    pub pub_date: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct UpdatePrefs {
+    #[serde(default)]
+    skipped_version: Option<String>,
+}
+
+fn prefs_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to resolve app config dir: {}", e))?;
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create config dir: {}", e))?;
+    Ok(dir.join("update-prefs.json"))
+}
+
+fn load_prefs(app: &AppHandle) -> UpdatePrefs {
+    let Ok(path) = prefs_path(app) else { return UpdatePrefs::default() };
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_prefs(app: &AppHandle, prefs: &UpdatePrefs) -> Result<(), String> {
+    let path = prefs_path(app)?;
+    let content = serde_json::to_string(prefs).map_err(|e| e.to_string())?;
+    std::fs::write(path, content).map_err(|e| format!("Failed to write update prefs: {}", e))
+}
+
+/// Query the configured release endpoint for a newer version than what's
+/// currently installed. Returns `available: false` if the caller already
+/// skipped this version via `skip_update_version`.
+#[tauri::command]
+pub async fn check_for_update(app: AppHandle) -> Result<UpdateInfo, String> {
+    let update = app
+        .updater()
+        .map_err(|e| format!("Failed to construct updater: {}", e))?
+        .check()
+        .await
+        .map_err(|e| format!("Failed to check for update: {}", e))?;
+
+    let Some(update) = update else {
+        return Ok(UpdateInfo {
+            available: false,
+            version: None,
+            notes: None,
+            pub_date: None,
+        });
+    };
+
+    let prefs = load_prefs(&app);
+    if prefs.skipped_version.as_deref() == Some(update.version.as_str()) {
+        return Ok(UpdateInfo {
+            available: false,
+            version: Some(update.version),
+            notes: update.body,
+            pub_date: update.date.map(|d| d.to_string()),
+        });
+    }
+
+    Ok(UpdateInfo {
+        available: true,
+        version: Some(update.version),
+        notes: update.body,
+        pub_date: update.date.map(|d| d.to_string()),
+    })
+}
+
+/// Download and apply the pending update, emitting `update-progress`,
+/// `update-ready`, and `update-error` events so the UI can render a download
+/// bar and a "restart to update" prompt instead of a blocking modal.
+#[tauri::command]
+pub async fn install_update(app: AppHandle) -> Result<(), String> {
+    let update = app
+        .updater()
+        .map_err(|e| format!("Failed to construct updater: {}", e))?
+        .check()
+        .await
+        .map_err(|e| format!("Failed to check for update: {}", e))?
+        .ok_or("No update available")?;
+
+    let app_for_progress = app.clone();
+    let app_for_error = app.clone();
+    let mut downloaded = 0u64;
+
+    let result = update
+        .download_and_install(
+            move |chunk_len, total| {
+                downloaded += chunk_len as u64;
+                let _ = app_for_progress.emit(
+                    "update-progress",
+                    serde_json::json!({ "downloaded": downloaded, "total": total }),
+                );
+            },
+            || {},
+        )
+        .await;
+
+    if let Err(e) = result {
+        let message = format!("Failed to install update: {}", e);
+        let _ = app_for_error.emit("update-error", &message);
+        return Err(message);
+    }
+
+    let _ = app.emit("update-ready", ());
+    Ok(())
+}
+
+/// Persist a "skip this version" preference so a dismissed update isn't
+/// re-nagged on the next `check_for_update`.
+#[tauri::command]
+pub fn skip_update_version(app: AppHandle, version: String) -> Result<(), String> {
+    let mut prefs = load_prefs(&app);
+    prefs.skipped_version = Some(version);
+    save_prefs(&app, &prefs)
+}